@@ -19,6 +19,7 @@ use rand::{
 };
 
 use std::marker::PhantomData;
+use std::io::{self, Read, Write};
 
 // Represents the affine point (X/Z, Y/Z) via the extended
 // twisted Edwards coordinates.
@@ -135,6 +136,106 @@ impl<E: JubjubEngine> Point<E, Unknown> {
             }
         }
     }
+
+    /// Recovers a point from its `y`-coordinate and the sign of `x`,
+    /// i.e. solves `x^2 = (y^2 - 1) / (d.y^2 + 1)` for `x` and picks the
+    /// root whose parity matches `sign`. The denominator is never zero
+    /// for a valid `y`, so the only failure mode is the quotient being
+    /// a non-residue, which means no point on the curve has this `y`.
+    pub fn get_for_y(y: E::Fr, sign: bool, params: &E::Params) -> Option<Self>
+    {
+        // Given a y on the curve, x^2 = (y^2 - 1) / (dy^2 + 1)
+        let mut y2 = y;
+        y2.square();
+
+        let mut n = y2;
+        n.sub_assign(&E::Fr::one());
+
+        let mut d = y2;
+        d.mul_assign(params.edwards_d());
+        d.add_assign(&E::Fr::one());
+
+        match d.inverse() {
+            Some(d_inv) => {
+                n.mul_assign(&d_inv);
+
+                n.sqrt().map(|mut x| {
+                    if x.into_repr().is_odd() != sign {
+                        x.negate();
+                    }
+
+                    let mut t = x;
+                    t.mul_assign(&y);
+
+                    Point {
+                        x: x,
+                        y: y,
+                        t: t,
+                        z: E::Fr::one(),
+                        _marker: PhantomData
+                    }
+                })
+            },
+            None => None
+        }
+    }
+
+    /// Writes the standard 32-byte compressed encoding: `y` least
+    /// significant byte first, with the top bit of the last byte
+    /// overloaded with the parity of the recovered `x`.
+    pub fn write<W: Write>(&self, writer: W) -> io::Result<()>
+    {
+        let (x, y) = self.into_xy();
+
+        let mut y_repr = y.into_repr();
+        if x.into_repr().is_odd() {
+            let top = y_repr.as_mut().len() - 1;
+            y_repr.as_mut()[top] |= 0x8000000000000000u64;
+        }
+
+        y_repr.write_le(writer)
+    }
+
+    /// Reads the standard 32-byte compressed encoding produced by
+    /// `write`, recovering `x` via `get_for_y`. Rejects encodings whose
+    /// `y` is not a canonical field element.
+    pub fn read<R: Read>(reader: R, params: &E::Params) -> io::Result<Self>
+    {
+        let mut y_repr = <E::Fr as PrimeField>::Repr::default();
+        y_repr.read_le(reader)?;
+
+        let top = y_repr.as_mut().len() - 1;
+        let sign = y_repr.as_ref()[top] >> 63 == 1;
+        y_repr.as_mut()[top] &= 0x7fffffffffffffff;
+
+        match E::Fr::from_repr(y_repr) {
+            Ok(y) => {
+                Self::get_for_y(y, sign, params)
+                    .ok_or(io::Error::new(io::ErrorKind::InvalidInput, "not on curve"))
+            },
+            Err(_) => Err(io::Error::new(io::ErrorKind::InvalidInput, "y is not in field"))
+        }
+    }
+
+    /// Decodes a compressed point and clears its cofactor in the same
+    /// pass, returning `None` if the result is the neutral element —
+    /// i.e. if the encoded point was one of the eight points of small
+    /// order that the curve's cofactor-8 structure admits, rather than
+    /// a genuine point of the prime-order subgroup.
+    pub fn decompress_into_subgroup<R: Read>(
+        reader: R,
+        params: &E::Params
+    ) -> io::Result<Option<Point<E, PrimeOrder>>>
+    {
+        let p = Self::read(reader, params)?;
+        let cleared = p.mul_by_cofactor(params);
+
+        if cleared == Point::zero() {
+            Ok(None)
+        } else {
+            Ok(Some(cleared))
+        }
+    }
 }
 
 impl<E: JubjubEngine, Subgroup> Point<E, Subgroup> {
@@ -257,6 +358,14 @@ impl<E: JubjubEngine, Subgroup> Point<E, Subgroup> {
         }
     }
 
+    /// Checks whether `self` is one of the eight points of small order
+    /// admitted by the curve's cofactor-8 structure, without paying for
+    /// a full `mul` by the subgroup order: such a point vanishes after
+    /// three doublings (i.e. multiplication by the cofactor).
+    pub fn is_small_order(&self, params: &E::Params) -> bool {
+        self.double(params).double(params).double(params) == Point::zero()
+    }
+
     pub fn zero() -> Self {
         Point {
             x: E::Fr::zero(),
@@ -280,6 +389,85 @@ impl<E: JubjubEngine, Subgroup> Point<E, Subgroup> {
         (x, y)
     }
 
+    /// Converts many points to affine `(x, y)` coordinates at once using
+    /// Montgomery's trick, trading the `N` inversions that `into_xy` would
+    /// cost one-by-one for a single inversion plus a handful of
+    /// multiplications per point.
+    pub fn batch_into_xy(points: &[Self]) -> Vec<(E::Fr, E::Fr)>
+    {
+        // Forward pass: prefixes[i] = z_0 * z_1 * ... * z_i (points with
+        // z = 0 are skipped so they can't poison the running product;
+        // such points are not valid curve points to begin with).
+        let mut prefixes = Vec::with_capacity(points.len());
+        let mut acc = E::Fr::one();
+
+        for p in points {
+            if !p.z.is_zero() {
+                acc.mul_assign(&p.z);
+            }
+            prefixes.push(acc);
+        }
+
+        let mut running = match acc.inverse() {
+            Some(inv) => inv,
+            None => E::Fr::zero()
+        };
+
+        let mut result = vec![(E::Fr::zero(), E::Fr::zero()); points.len()];
+
+        // Backward pass: recover each z_i^{-1} from the running inverse
+        // and the previous prefix, then unwind it out of `running`.
+        for i in (0..points.len()).rev() {
+            if points[i].z.is_zero() {
+                continue;
+            }
+
+            let zinv = if i == 0 {
+                running
+            } else {
+                let mut t = running;
+                t.mul_assign(&prefixes[i - 1]);
+                t
+            };
+
+            running.mul_assign(&points[i].z);
+
+            let mut x = points[i].x;
+            x.mul_assign(&zinv);
+
+            let mut y = points[i].y;
+            y.mul_assign(&zinv);
+
+            result[i] = (x, y);
+        }
+
+        result
+    }
+
+    /// Normalizes many points to `z = 1` in place, using the same batched
+    /// inversion as `batch_into_xy`. A degenerate point (`z = 0`, not a
+    /// valid curve point to begin with) has no affine representation, so
+    /// it is left untouched rather than overwritten with a fabricated
+    /// `(0, 0)` that wouldn't satisfy the curve equation.
+    pub fn batch_normalize(points: &mut [Self])
+    {
+        let affine = Self::batch_into_xy(points);
+
+        for (p, (x, y)) in points.iter_mut().zip(affine.into_iter()) {
+            if p.z.is_zero() {
+                continue;
+            }
+
+            let mut t = x;
+            t.mul_assign(&y);
+
+            p.x = x;
+            p.y = y;
+            p.t = t;
+            p.z = E::Fr::one();
+        }
+    }
+
     pub fn negate(&self) -> Self {
         let mut p = self.clone();
 
@@ -379,4 +567,345 @@ impl<E: JubjubEngine, Subgroup> Point<E, Subgroup> {
 
         res
     }
+
+    /// Constant-time scalar multiplication via a Montgomery ladder.
+    ///
+    /// Unlike `mul`, the sequence of field operations performed does not
+    /// depend on the bits of `scalar`, which matters for signing and key
+    /// derivation where the scalar is secret. Because the addition law
+    /// above is unified and complete, the ladder needs no exceptional-case
+    /// handling.
+    pub fn mul_ct<S: Into<<E::Fs as PrimeField>::Repr>>(
+        &self,
+        scalar: S,
+        params: &E::Params
+    ) -> Self
+    {
+        // Invariant maintained through the loop: r1 = r0 + self.
+        let mut r0 = Self::zero();
+        let mut r1 = self.clone();
+
+        for b in BitIterator::new(scalar.into()) {
+            Self::conditional_swap(b, &mut r0, &mut r1);
+            r1 = r0.add(&r1, params);
+            r0 = r0.double(params);
+            Self::conditional_swap(b, &mut r0, &mut r1);
+        }
+
+        r0
+    }
+
+    /// Swaps `a` and `b` in constant time when `bit` is set, via a
+    /// branch-free mask-and-xor select over each coordinate's limbs.
+    fn conditional_swap(bit: bool, a: &mut Self, b: &mut Self)
+    {
+        conditional_swap_field(bit, &mut a.x, &mut b.x);
+        conditional_swap_field(bit, &mut a.y, &mut b.y);
+        conditional_swap_field(bit, &mut a.t, &mut b.t);
+        conditional_swap_field(bit, &mut a.z, &mut b.z);
+    }
+
+    /// Computes `∑ scalars[i] * points[i]` using Straus's interleaved-window
+    /// method, sharing one doubling chain across all the terms instead of
+    /// running `N` independent `mul` calls.
+    ///
+    /// This is variable-time: the per-window table lookups are indexed
+    /// directly by the scalars' bits, so it is meant for public-input
+    /// verification (e.g. combining commitment openings), never for
+    /// scalars that need to stay secret — use `mul_ct` for those.
+    /// Returns `None` if `scalars` and `points` have different lengths.
+    pub fn multiscalar_mul(
+        scalars: &[<E::Fs as PrimeField>::Repr],
+        points: &[Self],
+        params: &E::Params
+    ) -> Option<Self>
+    {
+        if scalars.len() != points.len() {
+            return None;
+        }
+
+        const WINDOW: usize = 4;
+        const TABLE_SIZE: usize = (1 << WINDOW) - 1;
+
+        // tables[i][k] = (k + 1) * points[i], for k in 0..TABLE_SIZE.
+        let tables: Vec<Vec<Self>> = points.iter().map(|p| {
+            let mut table = Vec::with_capacity(TABLE_SIZE);
+            table.push(p.clone());
+            for k in 1..TABLE_SIZE {
+                table.push(table[k - 1].add(p, params));
+            }
+            table
+        }).collect();
+
+        let bits = E::Fs::NUM_BITS as usize;
+        let num_windows = (bits + WINDOW - 1) / WINDOW;
+
+        let mut acc = Self::zero();
+
+        for w in (0..num_windows).rev() {
+            for _ in 0..WINDOW {
+                acc = acc.double(params);
+            }
+
+            for (scalar, table) in scalars.iter().zip(tables.iter()) {
+                let window = window_bits(scalar, w * WINDOW, WINDOW);
+
+                if window != 0 {
+                    acc = acc.add(&table[window as usize - 1], params);
+                }
+            }
+        }
+
+        Some(acc)
+    }
+}
+
+/// Extracts the `width`-bit window starting at `bit_offset` from a
+/// field element's little-endian limb representation.
+fn window_bits<R: AsRef<[u64]>>(repr: &R, bit_offset: usize, width: usize) -> u64
+{
+    let limbs = repr.as_ref();
+    let mut window = 0u64;
+
+    for i in 0..width {
+        let bit_idx = bit_offset + i;
+        let limb = bit_idx / 64;
+        let shift = bit_idx % 64;
+
+        if limb < limbs.len() {
+            window |= ((limbs[limb] >> shift) & 1) << i;
+        }
+    }
+
+    window
+}
+
+/// Branch-free conditional swap of two field elements, implemented as a
+/// mask-and-xor select over their limb representations so that the same
+/// instructions execute regardless of `bit`.
+fn conditional_swap_field<F: PrimeField>(bit: bool, a: &mut F, b: &mut F)
+{
+    let mask = 0u64.wrapping_sub(bit as u64);
+
+    let mut a_repr = a.into_repr();
+    let mut b_repr = b.into_repr();
+
+    for (x, y) in a_repr.as_mut().iter_mut().zip(b_repr.as_mut().iter_mut()) {
+        let t = mask & (*x ^ *y);
+        *x ^= t;
+        *y ^= t;
+    }
+
+    *a = F::from_repr(a_repr).expect("swapping limbs of valid field elements stays in field");
+    *b = F::from_repr(b_repr).expect("swapping limbs of valid field elements stays in field");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::JubjubBls12;
+    use pairing::bls12_381::{Bls12, Fr};
+    use rand::{SeedableRng, XorShiftRng};
+
+    fn test_rng() -> XorShiftRng {
+        XorShiftRng::from_seed([0x3dbe6258, 0x8d313d76, 0x3237db17, 0xe5bc0654])
+    }
+
+    #[test]
+    fn test_compressed_round_trip() {
+        let params = &JubjubBls12::new();
+        let rng = &mut test_rng();
+
+        for _ in 0..100 {
+            let p = Point::<Bls12, Unknown>::rand(rng, params);
+
+            let mut bytes = vec![];
+            p.write(&mut bytes).unwrap();
+            assert_eq!(bytes.len(), 32);
+
+            let q = Point::<Bls12, Unknown>::read(&bytes[..], params).unwrap();
+            assert_eq!(p, q);
+        }
+    }
+
+    #[test]
+    fn test_read_rejects_noncanonical_y() {
+        let params = &JubjubBls12::new();
+
+        // Fr's modulus is smaller than 2^255, so the all-ones encoding
+        // (with the sign bit cleared) is not a canonical field element.
+        let mut bytes = vec![0xffu8; 32];
+        bytes[31] &= 0x7f;
+
+        assert!(Point::<Bls12, Unknown>::read(&bytes[..], params).is_err());
+    }
+
+    #[test]
+    fn test_get_for_y_rejects_non_residue() {
+        let params = &JubjubBls12::new();
+        let rng = &mut test_rng();
+
+        let mut saw_non_residue = false;
+        for _ in 0..1000 {
+            let y: Fr = rng.gen();
+            if Point::<Bls12, Unknown>::get_for_y(y, rng.gen(), params).is_none() {
+                saw_non_residue = true;
+                break;
+            }
+        }
+
+        assert!(saw_non_residue, "expected a non-residue y within 1000 samples");
+    }
+
+    #[test]
+    fn test_mul_ct_matches_mul() {
+        let params = &JubjubBls12::new();
+        let rng = &mut test_rng();
+
+        type Fs = <Bls12 as JubjubEngine>::Fs;
+
+        let p = Point::<Bls12, Unknown>::rand(rng, params);
+
+        let mut reprs = vec![Fs::zero().into_repr(), Fs::one().into_repr(), Fs::char()];
+        for _ in 0..10 {
+            let s: Fs = rng.gen();
+            reprs.push(s.into_repr());
+        }
+
+        for repr in reprs {
+            assert_eq!(p.mul(repr, params), p.mul_ct(repr, params));
+        }
+    }
+
+    #[test]
+    fn test_batch_into_xy_matches_into_xy() {
+        let params = &JubjubBls12::new();
+        let rng = &mut test_rng();
+
+        let points: Vec<Point<Bls12, Unknown>> = (0..10)
+            .map(|_| Point::rand(rng, params))
+            .collect();
+
+        let batched = Point::batch_into_xy(&points);
+
+        for (p, (x, y)) in points.iter().zip(batched.iter()) {
+            let (ex, ey) = p.into_xy();
+            assert_eq!(*x, ex);
+            assert_eq!(*y, ey);
+        }
+    }
+
+    #[test]
+    fn test_batch_normalize_leaves_degenerate_point_untouched() {
+        let params = &JubjubBls12::new();
+        let rng = &mut test_rng();
+
+        let degenerate = Point::<Bls12, Unknown> {
+            x: Fr::zero(),
+            y: Fr::zero(),
+            t: Fr::zero(),
+            z: Fr::zero(),
+            _marker: PhantomData
+        };
+
+        let mut points = vec![Point::rand(rng, params), degenerate];
+        let expected_xy = points[0].into_xy();
+
+        Point::batch_normalize(&mut points);
+
+        assert_eq!(points[0].into_xy(), expected_xy);
+        assert_eq!(points[0].z, Fr::one());
+
+        // The degenerate point has no affine form; it must be left alone
+        // rather than overwritten with a fabricated, invalid point.
+        assert!(points[1].z.is_zero());
+        assert!(points[1].x.is_zero());
+        assert!(points[1].y.is_zero());
+    }
+
+    #[test]
+    fn test_multiscalar_mul_matches_individual_muls() {
+        let params = &JubjubBls12::new();
+        let rng = &mut test_rng();
+
+        type Fs = <Bls12 as JubjubEngine>::Fs;
+
+        let points: Vec<Point<Bls12, Unknown>> = (0..5)
+            .map(|_| Point::rand(rng, params))
+            .collect();
+        let scalars: Vec<Fs> = (0..5).map(|_| rng.gen()).collect();
+        let reprs: Vec<_> = scalars.iter().map(|s| s.into_repr()).collect();
+
+        let mut expected = Point::zero();
+        for (s, p) in scalars.iter().zip(points.iter()) {
+            expected = expected.add(&p.mul(s.into_repr(), params), params);
+        }
+
+        let actual = Point::multiscalar_mul(&reprs, &points, params).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_multiscalar_mul_rejects_length_mismatch() {
+        let params = &JubjubBls12::new();
+        let rng = &mut test_rng();
+
+        type Fs = <Bls12 as JubjubEngine>::Fs;
+
+        let points: Vec<Point<Bls12, Unknown>> = (0..3)
+            .map(|_| Point::rand(rng, params))
+            .collect();
+        let reprs: Vec<_> = (0..2)
+            .map(|_| { let s: Fs = rng.gen(); s.into_repr() })
+            .collect();
+
+        assert!(Point::multiscalar_mul(&reprs, &points, params).is_none());
+    }
+
+    #[test]
+    fn test_low_order_points_are_small_order_and_rejected_on_decompress() {
+        let params = &JubjubBls12::new();
+
+        let mut neg_one = Fr::one();
+        neg_one.negate();
+
+        // The point of order 2: `from_montgomery` maps the Montgomery
+        // curve's own order-2 point (0, 0) to this Edwards point.
+        let order_two = Point::<Bls12, Unknown> {
+            x: Fr::zero(),
+            y: neg_one,
+            t: Fr::zero(),
+            z: Fr::one(),
+            _marker: PhantomData
+        };
+
+        for p in vec![Point::<Bls12, Unknown>::zero(), order_two] {
+            assert!(p.is_small_order(params));
+
+            let mut bytes = vec![];
+            p.write(&mut bytes).unwrap();
+
+            let decoded = Point::<Bls12, Unknown>::decompress_into_subgroup(&bytes[..], params).unwrap();
+            assert!(decoded.is_none());
+        }
+    }
+
+    #[test]
+    fn test_decompress_into_subgroup_accepts_prime_order_points() {
+        let params = &JubjubBls12::new();
+        let rng = &mut test_rng();
+
+        for _ in 0..20 {
+            let p = Point::<Bls12, Unknown>::rand(rng, params).mul_by_cofactor(params);
+            let p: Point<Bls12, Unknown> = p.into();
+            assert!(!p.is_small_order(params));
+
+            let mut bytes = vec![];
+            p.write(&mut bytes).unwrap();
+
+            let decoded = Point::<Bls12, Unknown>::decompress_into_subgroup(&bytes[..], params).unwrap();
+            assert!(decoded.is_some());
+        }
+    }
 }
\ No newline at end of file